@@ -1,36 +1,46 @@
+use crate::components::fps::FpsCounter;
 use crate::components::log::LogComponent;
 use crate::components::main::MainComponent;
 use crate::components::Component;
+use crate::config::{key_event_to_string, Config};
 use crate::tui::{io, Tui};
 use crate::types::{Action, Event};
 use color_eyre::Result;
-use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use crossterm::event::KeyEvent;
+use nix::sys::signal;
+use nix::unistd;
 use ratatui::backend::CrosstermBackend;
-use ratatui::layout::{Constraint, Layout};
+use ratatui::layout::{Constraint, Layout, Rect};
 use ratatui::Terminal;
 use tokio::sync::mpsc;
 
 pub struct App {
     frame_rate: f64,
+    config: Config,
     main_component: MainComponent,
     components: Vec<Box<dyn Component>>,
+    last_tick_key_events: Vec<KeyEvent>,
+    main_area: Rect,
 }
 
 impl App {
-    pub fn new(frame_rate: f64) -> Self {
+    pub fn new(frame_rate: f64) -> Result<Self> {
         log::debug!("App::new(frame_rate: {frame_rate})");
-        Self {
+        Ok(Self {
             frame_rate,
+            config: Config::new()?,
             main_component: MainComponent::new(),
-            components: Vec::new(),
-        }
+            components: vec![Box::new(FpsCounter::new())],
+            last_tick_key_events: Vec::new(),
+            main_area: Rect::default(),
+        })
     }
     pub async fn run(&mut self) -> Result<()> {
         let (action_tx, mut action_rx) = mpsc::unbounded_channel();
 
         let terminal = Terminal::new(CrosstermBackend::new(io()))?;
         log::debug!("terminal size: {}", terminal.size()?);
-        let mut tui = Tui::new(terminal);
+        let mut tui = Tui::new(terminal).mouse(self.config.mouse_capture);
         tui.start(self.frame_rate)?;
 
         self.main_component
@@ -38,7 +48,11 @@ impl App {
         for component in self.components.iter_mut() {
             component.register_action_handler(action_tx.clone())?;
         }
-        // TODO: config handler?
+        self.main_component
+            .register_config_handler(self.config.clone())?;
+        for component in self.components.iter_mut() {
+            component.register_config_handler(self.config.clone())?;
+        }
         self.main_component.init_async(tui.size()?).await?;
         self.main_component.init(tui.size()?)?;
         for component in self.components.iter_mut() {
@@ -46,8 +60,13 @@ impl App {
         }
 
         let mut should_quit = false;
+        let mut should_suspend = false;
         loop {
             if let Some(e) = tui.next_event().await {
+                // Mouse coordinates come in terminal-global; components only
+                // know their own local Rect, so rebase clicks/scrolls onto
+                // main_area before anything sees them.
+                let e = self.translate_mouse_event(e);
                 if let Some(action) = self.handle_events(e.clone()) {
                     action_tx.send(action)?;
                 }
@@ -66,19 +85,53 @@ impl App {
                 }
                 match action {
                     Action::Quit => should_quit = true,
+                    Action::Suspend => should_suspend = true,
+                    Action::Resume => should_suspend = false,
                     Action::Tick(i) => {
                         log::debug!("tick {i}");
+                        self.last_tick_key_events.clear();
                         if i % 10 == 0 {
                             self.save().await?;
                         }
                     }
+                    Action::EditPost => {
+                        // Tear down the event task and terminal state before
+                        // handing the screen to $EDITOR, so crossterm and the
+                        // child process aren't both fighting over the tty.
+                        tui.exit()?;
+                        tui.stop()?;
+                        let draft = self.main_component.draft();
+                        match self.edit_in_external_editor(draft).await {
+                            Ok(text) => action_tx.send(Action::PostDrafted(text))?,
+                            Err(e) => {
+                                action_tx.send(Action::Error(format!(
+                                    "failed to launch editor: {e:?}"
+                                )))?;
+                            }
+                        }
+                        // Always restore the TUI, even on editor failure.
+                        tui.start(self.frame_rate)?;
+                        action_tx.send(Action::Render)?;
+                    }
                     Action::Render => {
+                        let show_log_panel = self.main_component.show_log_panel();
+                        let log_panel_width = self.main_component.log_panel_width();
+                        let constraints = if show_log_panel {
+                            vec![Constraint::Fill(1), Constraint::Max(log_panel_width)]
+                        } else {
+                            vec![Constraint::Fill(1)]
+                        };
+                        let layout = Layout::default()
+                            .direction(ratatui::layout::Direction::Horizontal)
+                            .constraints(constraints)
+                            .split(tui.size()?);
+                        // Remembered so the next mouse event can be rebased
+                        // into this area's local coordinates.
+                        self.main_area = layout[0];
                         tui.draw(|f| {
                             // split horizontally, the right side is for log view
-                            let layout = Layout::default()
-                                .direction(ratatui::layout::Direction::Horizontal)
-                                .constraints([Constraint::Fill(1), Constraint::Max(75)])
-                                .split(f.size());
+                            // (unless the user has hidden it, in which case the
+                            // main view takes the full width)
                             // render main components to the left side
                             if let Err(e) = self.main_component.draw(f, layout[0]) {
                                 action_tx
@@ -86,10 +139,12 @@ impl App {
                                     .expect("failed to send error");
                             }
                             // render log components to the right side
-                            if let Err(e) = LogComponent.draw(f, layout[1]) {
-                                action_tx
-                                    .send(Action::Error(format!("failed to draw: {e:?}")))
-                                    .expect("failed to send error");
+                            if show_log_panel {
+                                if let Err(e) = LogComponent.draw(f, layout[1]) {
+                                    action_tx
+                                        .send(Action::Error(format!("failed to draw: {e:?}")))
+                                        .expect("failed to send error");
+                                }
                             }
                             // other components?
                             for component in self.components.iter_mut() {
@@ -101,19 +156,36 @@ impl App {
                             }
                         })?;
                     }
-                    _ => {
-                        if let Some(action) = self.main_component.update(action.clone())? {
-                            action_tx.send(action)?;
-                        }
-                        for component in self.components.iter_mut() {
-                            if let Some(action) = component.update(action.clone())? {
-                                action_tx.send(action)?;
-                            }
-                        }
+                    _ => {}
+                }
+                // Every action (including Tick/Render, handled above) also
+                // reaches each component's own update, e.g. so FpsCounter can
+                // accumulate tick/render counts.
+                if let Some(action) = self.main_component.update(action.clone())? {
+                    action_tx.send(action)?;
+                }
+                for component in self.components.iter_mut() {
+                    if let Some(action) = component.update(action.clone())? {
+                        action_tx.send(action)?;
                     }
                 }
             }
-            if should_quit {
+            if should_suspend {
+                tui.suspend()?;
+                // Signal our own process group, not just this process, so a
+                // shell backgrounding the whole pipeline suspends it all.
+                signal::killpg(unistd::getpgrp(), signal::Signal::SIGTSTP)?;
+                // Execution resumes here once the shell sends SIGCONT.
+                tui.resume()?;
+                self.main_component
+                    .register_action_handler(action_tx.clone())?;
+                for component in self.components.iter_mut() {
+                    component.register_action_handler(action_tx.clone())?;
+                }
+                should_suspend = false;
+                action_tx.send(Action::Resume)?;
+                action_tx.send(Action::Render)?;
+            } else if should_quit {
                 break self.save().await?;
             }
         }
@@ -123,6 +195,44 @@ impl App {
     async fn save(&self) -> Result<()> {
         self.main_component.save().await
     }
+
+    /// Rebases a mouse event's terminal-global coordinates onto `main_area`
+    /// so components doing hit-testing (click-to-focus, scroll) can work in
+    /// their own local `Rect` space without knowing about the log pane split.
+    fn translate_mouse_event(&self, event: Event) -> Event {
+        if let Event::Mouse(mut mouse) = event {
+            mouse.column = mouse.column.saturating_sub(self.main_area.x);
+            mouse.row = mouse.row.saturating_sub(self.main_area.y);
+            Event::Mouse(mouse)
+        } else {
+            event
+        }
+    }
+
+    /// Writes `draft` to a tempfile, runs `$EDITOR` (falling back to
+    /// `$VISUAL`, then `vi`) on it as a blocking child process, and reads
+    /// the result back. The terminal must already be restored to normal
+    /// mode by the caller before this runs.
+    async fn edit_in_external_editor(&self, draft: String) -> Result<String> {
+        let mut file = tempfile::Builder::new().suffix(".md").tempfile()?;
+        std::io::Write::write_all(&mut file, draft.as_bytes())?;
+
+        let editor = std::env::var("EDITOR")
+            .or_else(|_| std::env::var("VISUAL"))
+            .unwrap_or_else(|_| "vi".to_string());
+        let path = file.path().to_path_buf();
+
+        let status = tokio::task::spawn_blocking(move || {
+            std::process::Command::new(editor).arg(&path).status()
+        })
+        .await??;
+
+        if !status.success() {
+            return Err(color_eyre::eyre::eyre!("editor exited with {status}"));
+        }
+
+        Ok(std::fs::read_to_string(file.path())?)
+    }
     fn handle_events(&mut self, event: Event) -> Option<Action> {
         match event {
             Event::Tick(i) => return Some(Action::Tick(i)),
@@ -136,11 +246,34 @@ impl App {
         }
         None
     }
+    /// Buffers keys seen since the last tick and matches the buffer against
+    /// the active mode's keybinding table, so multi-key chords like
+    /// `"<g><g>"` work: an exact match fires the action and clears the
+    /// buffer, a prefix match keeps waiting for the next key, and no match
+    /// at all clears the buffer and drops the keystroke.
     fn handle_key_events(&mut self, key_event: KeyEvent) -> Option<Action> {
-        if matches!(key_event.code, KeyCode::Char('c' | 'q'))
-            && key_event.modifiers == KeyModifiers::CONTROL
-        {
-            return Some(Action::Quit);
+        self.last_tick_key_events.push(key_event);
+
+        let mode = self.main_component.mode();
+        let bindings = self.config.keybindings.get(&mode)?;
+
+        if let Some(action) = bindings.get(&self.last_tick_key_events) {
+            self.last_tick_key_events.clear();
+            return Some(action.clone());
+        }
+
+        let is_prefix = bindings
+            .keys()
+            .any(|chord| chord.starts_with(&self.last_tick_key_events[..]));
+        if !is_prefix {
+            log::debug!(
+                "no binding for {:?} in {mode:?}",
+                self.last_tick_key_events
+                    .iter()
+                    .map(key_event_to_string)
+                    .collect::<Vec<_>>()
+            );
+            self.last_tick_key_events.clear();
         }
         None
     }
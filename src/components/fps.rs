@@ -0,0 +1,98 @@
+use std::time::Instant;
+
+use async_trait::async_trait;
+use color_eyre::Result;
+use ratatui::layout::{Alignment, Rect};
+use ratatui::style::{Color, Style};
+use ratatui::widgets::Paragraph;
+
+use super::Component;
+use crate::tui::Frame;
+use crate::types::Action;
+
+/// Measures tick rate and render rate by counting events until at least one
+/// second has passed, then recomputing the rate and restarting the count,
+/// and draws them as a small corner overlay. Hidden by default; toggled on with
+/// `Action::ToggleFpsCounter` for diagnosing sluggish redraws.
+pub struct FpsCounter {
+    shown: bool,
+    tick_count: u32,
+    render_count: u32,
+    last_tick_at: Instant,
+    last_render_at: Instant,
+    ticks_per_second: f64,
+    frames_per_second: f64,
+}
+
+impl Default for FpsCounter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FpsCounter {
+    pub fn new() -> Self {
+        let now = Instant::now();
+        Self {
+            shown: false,
+            tick_count: 0,
+            render_count: 0,
+            last_tick_at: now,
+            last_render_at: now,
+            ticks_per_second: 0.0,
+            frames_per_second: 0.0,
+        }
+    }
+}
+
+#[async_trait]
+impl Component for FpsCounter {
+    fn update(&mut self, action: Action) -> Result<Option<Action>> {
+        match action {
+            Action::ToggleFpsCounter => self.shown = !self.shown,
+            Action::Tick(_) => {
+                self.tick_count += 1;
+                let elapsed = self.last_tick_at.elapsed().as_secs_f64();
+                if elapsed >= 1.0 {
+                    self.ticks_per_second = f64::from(self.tick_count) / elapsed;
+                    self.tick_count = 0;
+                    self.last_tick_at = Instant::now();
+                }
+            }
+            Action::Render => {
+                self.render_count += 1;
+                let elapsed = self.last_render_at.elapsed().as_secs_f64();
+                if elapsed >= 1.0 {
+                    self.frames_per_second = f64::from(self.render_count) / elapsed;
+                    self.render_count = 0;
+                    self.last_render_at = Instant::now();
+                }
+            }
+            _ => {}
+        }
+        Ok(None)
+    }
+
+    fn draw(&mut self, f: &mut Frame, area: Rect) -> Result<()> {
+        if !self.shown || area.height == 0 {
+            return Ok(());
+        }
+        let corner = Rect {
+            x: area.x,
+            y: area.y,
+            width: area.width,
+            height: 1,
+        };
+        let text = format!(
+            "{:.1} ticks/sec, {:.1} fps",
+            self.ticks_per_second, self.frames_per_second
+        );
+        f.render_widget(
+            Paragraph::new(text)
+                .style(Style::default().fg(Color::DarkGray))
+                .alignment(Alignment::Right),
+            corner,
+        );
+        Ok(())
+    }
+}
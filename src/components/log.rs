@@ -0,0 +1,17 @@
+use color_eyre::Result;
+use ratatui::layout::Rect;
+use tui_logger::TuiLoggerWidget;
+
+use super::Component;
+use crate::tui::Frame;
+
+/// Renders the `log` crate's output via `tui-logger`. Stateless: it just
+/// reflects whatever has been logged elsewhere in the app.
+pub struct LogComponent;
+
+impl Component for LogComponent {
+    fn draw(&mut self, f: &mut Frame, area: Rect) -> Result<()> {
+        f.render_widget(TuiLoggerWidget::default(), area);
+        Ok(())
+    }
+}
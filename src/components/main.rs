@@ -0,0 +1,167 @@
+use std::path::PathBuf;
+
+use async_trait::async_trait;
+use color_eyre::Result;
+use crossterm::event::{MouseButton, MouseEventKind};
+use ratatui::layout::Rect;
+use ratatui::widgets::Paragraph;
+use serde::{Deserialize, Serialize};
+
+use super::Component;
+use crate::tui::Frame;
+use crate::types::{Action, Event, Mode};
+
+/// Top-level component: owns which screen (timeline/thread/compose/search)
+/// is active and persists its layout state across restarts.
+pub struct MainComponent {
+    mode: Mode,
+    draft: String,
+    layout: LayoutState,
+    /// How far the timeline has been scrolled; a stand-in for a real
+    /// post-list cursor until the timeline view lands.
+    scroll_offset: usize,
+}
+
+const MIN_LOG_PANEL_WIDTH: u16 = 20;
+const MAX_LOG_PANEL_WIDTH: u16 = 120;
+const LOG_PANEL_WIDTH_STEP: u16 = 5;
+
+/// The subset of `MainComponent`'s state that's about window layout rather
+/// than app data, round-tripped through `save()`; it is the only state
+/// `save()` writes.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct LayoutState {
+    show_log_panel: bool,
+    log_panel_width: u16,
+}
+
+impl Default for LayoutState {
+    fn default() -> Self {
+        Self {
+            show_log_panel: true,
+            log_panel_width: 75,
+        }
+    }
+}
+
+impl Default for MainComponent {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MainComponent {
+    pub fn new() -> Self {
+        Self {
+            mode: Mode::Timeline,
+            draft: String::new(),
+            layout: Self::load_layout().unwrap_or_default(),
+            scroll_offset: 0,
+        }
+    }
+
+    pub fn mode(&self) -> Mode {
+        self.mode
+    }
+
+    /// The post/reply text currently being composed, handed to `$EDITOR`
+    /// when the user fires `Action::EditPost`.
+    pub fn draft(&self) -> String {
+        self.draft.clone()
+    }
+
+    pub fn show_log_panel(&self) -> bool {
+        self.layout.show_log_panel
+    }
+
+    pub fn log_panel_width(&self) -> u16 {
+        self.layout.log_panel_width
+    }
+
+    pub async fn save(&self) -> Result<()> {
+        // TODO: persist timeline cursor, draft state, etc.
+        if let Some(path) = Self::state_path() {
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(path, ron::to_string(&self.layout)?)?;
+        }
+        Ok(())
+    }
+
+    fn load_layout() -> Option<LayoutState> {
+        let path = Self::state_path()?;
+        let contents = std::fs::read_to_string(path).ok()?;
+        ron::from_str(&contents).ok()
+    }
+
+    fn state_path() -> Option<PathBuf> {
+        directories::ProjectDirs::from("", "", "tuisky")
+            .map(|dirs| dirs.data_dir().join("state.ron"))
+    }
+}
+
+#[async_trait]
+impl Component for MainComponent {
+    fn handle_events(&mut self, event: Option<Event>) -> Result<Option<Action>> {
+        // `event`'s mouse coordinates have already been rebased by App onto
+        // our own Rect, so (column, row) here is local, not terminal-global.
+        if let Some(Event::Mouse(mouse)) = event {
+            match mouse.kind {
+                MouseEventKind::ScrollDown => {
+                    self.scroll_offset = self.scroll_offset.saturating_add(1);
+                }
+                MouseEventKind::ScrollUp => {
+                    self.scroll_offset = self.scroll_offset.saturating_sub(1);
+                }
+                MouseEventKind::Down(MouseButton::Left) => {
+                    log::debug!(
+                        "click at local ({}, {}) in timeline",
+                        mouse.column,
+                        mouse.row
+                    );
+                }
+                _ => {}
+            }
+        }
+        Ok(None)
+    }
+
+    fn update(&mut self, action: Action) -> Result<Option<Action>> {
+        match action {
+            // EditPost/PostDrafted bracket a trip through $EDITOR, so they
+            // double as the only mode transition wired up so far: Compose
+            // becomes reachable while composing, and Timeline's bindings
+            // come back once the draft is in hand. Thread and Search have
+            // no view to switch into yet, so their config.ron tables stay
+            // unreachable scaffolding until those land.
+            Action::EditPost => self.mode = Mode::Compose,
+            Action::PostDrafted(text) => {
+                self.draft = text;
+                self.mode = Mode::Timeline;
+            }
+            Action::ToggleLogPanel => self.layout.show_log_panel = !self.layout.show_log_panel,
+            Action::GrowLogPanel => {
+                self.layout.log_panel_width = self
+                    .layout
+                    .log_panel_width
+                    .saturating_add(LOG_PANEL_WIDTH_STEP)
+                    .min(MAX_LOG_PANEL_WIDTH);
+            }
+            Action::ShrinkLogPanel => {
+                self.layout.log_panel_width = self
+                    .layout
+                    .log_panel_width
+                    .saturating_sub(LOG_PANEL_WIDTH_STEP)
+                    .max(MIN_LOG_PANEL_WIDTH);
+            }
+            _ => {}
+        }
+        Ok(None)
+    }
+
+    fn draw(&mut self, f: &mut Frame, area: Rect) -> Result<()> {
+        f.render_widget(Paragraph::new("tuisky"), area);
+        Ok(())
+    }
+}
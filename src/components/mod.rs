@@ -0,0 +1,43 @@
+pub mod fps;
+pub mod log;
+pub mod main;
+
+use async_trait::async_trait;
+use color_eyre::Result;
+use ratatui::layout::Rect;
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::config::Config;
+use crate::tui::Frame;
+use crate::types::{Action, Event};
+
+/// A unit of the UI: owns its own state, reacts to actions, and draws
+/// itself into whatever `Rect` the app hands it.
+#[async_trait]
+pub trait Component {
+    fn register_action_handler(&mut self, _tx: UnboundedSender<Action>) -> Result<()> {
+        Ok(())
+    }
+
+    fn register_config_handler(&mut self, _config: Config) -> Result<()> {
+        Ok(())
+    }
+
+    fn init(&mut self, _area: Rect) -> Result<()> {
+        Ok(())
+    }
+
+    async fn init_async(&mut self, _area: Rect) -> Result<()> {
+        Ok(())
+    }
+
+    fn handle_events(&mut self, _event: Option<Event>) -> Result<Option<Action>> {
+        Ok(None)
+    }
+
+    fn update(&mut self, _action: Action) -> Result<Option<Action>> {
+        Ok(None)
+    }
+
+    fn draw(&mut self, f: &mut Frame, area: Rect) -> Result<()>;
+}
@@ -0,0 +1,287 @@
+use std::collections::HashMap;
+use std::ops::{Deref, DerefMut};
+use std::path::PathBuf;
+
+use color_eyre::eyre::eyre;
+use color_eyre::Result;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use serde::de::Deserializer;
+use serde::Deserialize;
+
+use crate::types::{Action, Mode};
+
+const DEFAULT_CONFIG: &str = include_str!("../config.ron");
+
+/// User-facing configuration. Loaded once in `App::new` and handed to
+/// every component via `register_config_handler`.
+#[derive(Clone, Debug, Deserialize)]
+pub struct Config {
+    #[serde(default)]
+    pub keybindings: KeyBindings,
+    /// Whether to ask the terminal for mouse events at all. Off for users
+    /// who rely on their terminal's native text selection instead.
+    #[serde(default = "default_mouse_capture")]
+    pub mouse_capture: bool,
+}
+
+fn default_mouse_capture() -> bool {
+    true
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            keybindings: KeyBindings::default(),
+            mouse_capture: default_mouse_capture(),
+        }
+    }
+}
+
+impl Config {
+    pub fn new() -> Result<Self> {
+        let mut config: Config = ron::from_str(DEFAULT_CONFIG)?;
+
+        if let Some(user_config) = Self::load_user_config()? {
+            for (mode, bindings) in user_config.keybindings.0 {
+                config.keybindings.0.entry(mode).or_default().extend(bindings);
+            }
+            config.mouse_capture = user_config.mouse_capture;
+        }
+
+        Ok(config)
+    }
+
+    fn load_user_config() -> Result<Option<Config>> {
+        let Some(path) = Self::config_path() else {
+            return Ok(None);
+        };
+        if !path.exists() {
+            return Ok(None);
+        }
+        let contents = std::fs::read_to_string(path)?;
+        Ok(Some(ron::from_str(&contents)?))
+    }
+
+    fn config_path() -> Option<PathBuf> {
+        directories::ProjectDirs::from("", "", "tuisky")
+            .map(|dirs| dirs.config_dir().join("config.ron"))
+    }
+}
+
+/// `Mode -> (key chord -> Action)`. Keyed by a parsed chord (`Vec<KeyEvent>`)
+/// rather than the raw string so lookups during key handling are cheap.
+#[derive(Clone, Debug, Default)]
+pub struct KeyBindings(pub HashMap<Mode, HashMap<Vec<KeyEvent>, Action>>);
+
+impl Deref for KeyBindings {
+    type Target = HashMap<Mode, HashMap<Vec<KeyEvent>, Action>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for KeyBindings {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<'de> Deserialize<'de> for KeyBindings {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw = HashMap::<Mode, HashMap<String, Action>>::deserialize(deserializer)?;
+
+        let keybindings = raw
+            .into_iter()
+            .map(|(mode, bindings)| {
+                let bindings = bindings
+                    .into_iter()
+                    .map(|(chord, action)| {
+                        let chord = parse_key_sequence(&chord).map_err(serde::de::Error::custom)?;
+                        Ok((chord, action))
+                    })
+                    .collect::<Result<_, D::Error>>()?;
+                Ok((mode, bindings))
+            })
+            .collect::<Result<_, D::Error>>()?;
+
+        Ok(KeyBindings(keybindings))
+    }
+}
+
+/// Parses a chord string like `"<Ctrl-d>"` or `"<g><g>"` into the sequence
+/// of key presses it represents.
+pub fn parse_key_sequence(raw: &str) -> Result<Vec<KeyEvent>> {
+    raw.split("><")
+        .map(|part| part.trim_matches(|c| c == '<' || c == '>'))
+        .map(parse_single_key_event)
+        .collect()
+}
+
+fn parse_single_key_event(raw: &str) -> Result<KeyEvent> {
+    let mut modifiers = KeyModifiers::NONE;
+    let mut rest = raw;
+    loop {
+        match rest.split_once('-') {
+            Some(("Ctrl", tail)) => {
+                modifiers |= KeyModifiers::CONTROL;
+                rest = tail;
+            }
+            Some(("Alt", tail)) => {
+                modifiers |= KeyModifiers::ALT;
+                rest = tail;
+            }
+            Some(("Shift", tail)) => {
+                modifiers |= KeyModifiers::SHIFT;
+                rest = tail;
+            }
+            _ => break,
+        }
+    }
+
+    let code = match rest {
+        "esc" => KeyCode::Esc,
+        "enter" => KeyCode::Enter,
+        "tab" => KeyCode::Tab,
+        "backspace" => KeyCode::Backspace,
+        "left" => KeyCode::Left,
+        "right" => KeyCode::Right,
+        "up" => KeyCode::Up,
+        "down" => KeyCode::Down,
+        "space" => KeyCode::Char(' '),
+        single if single.chars().count() == 1 => KeyCode::Char(single.chars().next().unwrap()),
+        other => return Err(eyre!("unrecognized key `{other}` in chord `{raw}`")),
+    };
+
+    Ok(KeyEvent::new(code, modifiers))
+}
+
+/// The inverse of [`parse_key_sequence`]'s single-key half, used to log
+/// the live `last_tick_key_events` buffer in the same `"<g><g>"` form as
+/// the chord strings in `config.ron`. Matching itself compares parsed
+/// `KeyEvent`s and does not go through this function.
+pub fn key_event_to_string(key_event: &KeyEvent) -> String {
+    let mut parts = Vec::new();
+    if key_event.modifiers.contains(KeyModifiers::CONTROL) {
+        parts.push("Ctrl".to_string());
+    }
+    if key_event.modifiers.contains(KeyModifiers::ALT) {
+        parts.push("Alt".to_string());
+    }
+    if key_event.modifiers.contains(KeyModifiers::SHIFT) {
+        parts.push("Shift".to_string());
+    }
+    let key = match key_event.code {
+        KeyCode::Esc => "esc".to_string(),
+        KeyCode::Enter => "enter".to_string(),
+        KeyCode::Tab => "tab".to_string(),
+        KeyCode::Backspace => "backspace".to_string(),
+        KeyCode::Left => "left".to_string(),
+        KeyCode::Right => "right".to_string(),
+        KeyCode::Up => "up".to_string(),
+        KeyCode::Down => "down".to_string(),
+        KeyCode::Char(' ') => "space".to_string(),
+        KeyCode::Char(c) => c.to_string(),
+        _ => "unknown".to_string(),
+    };
+    parts.push(key);
+    format!("<{}>", parts.join("-"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_plain_and_named_keys() {
+        assert_eq!(
+            parse_key_sequence("<g>").unwrap(),
+            vec![KeyEvent::new(KeyCode::Char('g'), KeyModifiers::NONE)]
+        );
+        assert_eq!(
+            parse_key_sequence("<esc>").unwrap(),
+            vec![KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE)]
+        );
+    }
+
+    #[test]
+    fn parses_multi_key_chords() {
+        assert_eq!(
+            parse_key_sequence("<g><g>").unwrap(),
+            vec![
+                KeyEvent::new(KeyCode::Char('g'), KeyModifiers::NONE),
+                KeyEvent::new(KeyCode::Char('g'), KeyModifiers::NONE),
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_stacked_modifiers() {
+        assert_eq!(
+            parse_key_sequence("<Ctrl-d>").unwrap(),
+            vec![KeyEvent::new(KeyCode::Char('d'), KeyModifiers::CONTROL)]
+        );
+        assert_eq!(
+            parse_key_sequence("<Ctrl-Alt-x>").unwrap(),
+            vec![KeyEvent::new(
+                KeyCode::Char('x'),
+                KeyModifiers::CONTROL | KeyModifiers::ALT
+            )]
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_keys() {
+        assert!(parse_key_sequence("<nope>").is_err());
+    }
+
+    #[test]
+    fn key_event_to_string_round_trips_through_parse() {
+        for chord in ["<g>", "<esc>", "<Ctrl-d>", "<Ctrl-Alt-x>"] {
+            let events = parse_key_sequence(chord).unwrap();
+            assert_eq!(events.len(), 1);
+            assert_eq!(key_event_to_string(&events[0]), chord);
+        }
+    }
+
+    #[test]
+    fn default_config_parses_and_binds_quit() {
+        let config: Config = ron::from_str(DEFAULT_CONFIG).unwrap();
+        let timeline = config.keybindings.get(&Mode::Timeline).unwrap();
+        let quit_chord = parse_key_sequence("<Ctrl-c>").unwrap();
+        assert_eq!(timeline.get(&quit_chord), Some(&Action::Quit));
+    }
+
+    #[test]
+    fn user_keybindings_merge_over_defaults_without_dropping_them() {
+        let mut config: Config = ron::from_str(DEFAULT_CONFIG).unwrap();
+        let user: Config = ron::from_str(
+            r#"(
+                keybindings: {
+                    Timeline: {
+                        "<Ctrl-c>": Help,
+                    },
+                },
+            )"#,
+        )
+        .unwrap();
+
+        for (mode, bindings) in user.keybindings.0 {
+            config.keybindings.0.entry(mode).or_default().extend(bindings);
+        }
+
+        let timeline = config.keybindings.get(&Mode::Timeline).unwrap();
+        assert_eq!(
+            timeline.get(&parse_key_sequence("<Ctrl-c>").unwrap()),
+            Some(&Action::Help)
+        );
+        // The override shouldn't have wiped out the rest of the defaults.
+        assert_eq!(
+            timeline.get(&parse_key_sequence("<Ctrl-l>").unwrap()),
+            Some(&Action::ToggleLogPanel)
+        );
+    }
+}
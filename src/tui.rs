@@ -0,0 +1,225 @@
+use std::io::Stdout;
+use std::ops::{Deref, DerefMut};
+use std::time::Duration;
+
+use color_eyre::Result;
+use crossterm::event::{
+    DisableMouseCapture, EnableMouseCapture, Event as CrosstermEvent, EventStream, KeyEventKind,
+};
+use crossterm::terminal::{EnterAlternateScreen, LeaveAlternateScreen};
+use crossterm::ExecutableCommand;
+use futures::{FutureExt, StreamExt};
+use ratatui::backend::CrosstermBackend;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
+
+use crate::types::Event;
+
+pub fn io() -> Stdout {
+    std::io::stdout()
+}
+
+pub type Frame<'a> = ratatui::Frame<'a>;
+
+/// Wraps the ratatui `Terminal` plus the background task that turns
+/// crossterm input into our own [`Event`]s and ticks/renders on a timer.
+pub struct Tui {
+    pub terminal: ratatui::Terminal<CrosstermBackend<Stdout>>,
+    pub task: JoinHandle<()>,
+    pub cancellation_token: CancellationToken,
+    pub event_rx: mpsc::UnboundedReceiver<Event>,
+    pub event_tx: mpsc::UnboundedSender<Event>,
+    pub frame_rate: f64,
+    pub tick_rate: f64,
+    pub mouse: bool,
+}
+
+impl Tui {
+    pub fn new(terminal: ratatui::Terminal<CrosstermBackend<Stdout>>) -> Self {
+        let (event_tx, event_rx) = mpsc::unbounded_channel();
+        Self {
+            terminal,
+            task: tokio::spawn(async {}),
+            cancellation_token: CancellationToken::new(),
+            event_rx,
+            event_tx,
+            frame_rate: 60.0,
+            tick_rate: 4.0,
+            mouse: false,
+        }
+    }
+
+    pub fn mouse(mut self, mouse: bool) -> Self {
+        self.mouse = mouse;
+        self
+    }
+
+    pub fn start(&mut self, frame_rate: f64) -> Result<()> {
+        self.frame_rate = frame_rate;
+        self.cancel();
+        self.cancellation_token = CancellationToken::new();
+        self.enter()?;
+        self.spawn_event_task();
+        Ok(())
+    }
+
+    fn spawn_event_task(&mut self) {
+        let tick_delay = Duration::from_secs_f64(1.0 / self.tick_rate);
+        let render_delay = Duration::from_secs_f64(1.0 / self.frame_rate);
+        let _event_tx = self.event_tx.clone();
+        let _cancellation_token = self.cancellation_token.clone();
+        self.task = tokio::spawn(async move {
+            let mut reader = EventStream::new();
+            let mut tick_interval = tokio::time::interval(tick_delay);
+            let mut render_interval = tokio::time::interval(render_delay);
+            let mut tick = 0u64;
+            _event_tx.send(Event::Init).ok();
+            loop {
+                let tick_delay = tick_interval.tick();
+                let render_delay = render_interval.tick();
+                let crossterm_event = reader.next().fuse();
+                tokio::select! {
+                    _ = _cancellation_token.cancelled() => break,
+                    maybe_event = crossterm_event => {
+                        match maybe_event {
+                            Some(Ok(evt)) => {
+                                let mapped = match evt {
+                                    CrosstermEvent::Key(key) if key.kind == KeyEventKind::Press => {
+                                        Some(Event::Key(key))
+                                    }
+                                    CrosstermEvent::Key(_) => None,
+                                    CrosstermEvent::Mouse(mouse) => Some(Event::Mouse(mouse)),
+                                    CrosstermEvent::Resize(x, y) => Some(Event::Resize(x, y)),
+                                    CrosstermEvent::FocusGained => Some(Event::FocusGained),
+                                    CrosstermEvent::FocusLost => Some(Event::FocusLost),
+                                    CrosstermEvent::Paste(text) => Some(Event::Paste(text)),
+                                };
+                                if let Some(event) = mapped {
+                                    _event_tx.send(event).ok();
+                                }
+                            }
+                            Some(Err(_)) => {
+                                _event_tx.send(Event::Error).ok();
+                            }
+                            None => break,
+                        }
+                    }
+                    _ = tick_delay => {
+                        tick += 1;
+                        _event_tx.send(Event::Tick(tick)).ok();
+                    }
+                    _ = render_delay => {
+                        _event_tx.send(Event::Render).ok();
+                    }
+                }
+            }
+        });
+    }
+
+    /// Cancels the event task and blocks until it has finished, so callers
+    /// that are about to hand the terminal to something else (an external
+    /// editor, the shell after SIGTSTP) don't race crossterm's background
+    /// stdin reader for the tty. Cancellation is cooperative -- the task
+    /// only notices between `select!` polls -- so we poll `is_finished()`
+    /// and abort the task outright after about 50ms. If it still hasn't
+    /// finished after about 100ms, an error is logged and this returns
+    /// anyway.
+    pub fn stop(&mut self) -> Result<()> {
+        self.cancel();
+        let mut counter = 0;
+        while !self.task.is_finished() {
+            std::thread::sleep(Duration::from_millis(1));
+            counter += 1;
+            if counter > 50 {
+                self.task.abort();
+            }
+            if counter > 100 {
+                log::error!("failed to abort the tui event task after 100ms");
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    fn cancel(&self) {
+        self.cancellation_token.cancel();
+    }
+
+    pub fn enter(&mut self) -> Result<()> {
+        crossterm::terminal::enable_raw_mode()?;
+        io().execute(EnterAlternateScreen)?;
+        if self.mouse {
+            io().execute(EnableMouseCapture)?;
+        }
+        Ok(())
+    }
+
+    pub fn exit(&mut self) -> Result<()> {
+        if crossterm::terminal::is_raw_mode_enabled()? {
+            if self.mouse {
+                io().execute(DisableMouseCapture)?;
+            }
+            io().execute(LeaveAlternateScreen)?;
+            crossterm::terminal::disable_raw_mode()?;
+        }
+        Ok(())
+    }
+
+    pub fn suspend(&mut self) -> Result<()> {
+        self.stop()?;
+        self.exit()?;
+        Ok(())
+    }
+
+    pub fn resume(&mut self) -> Result<()> {
+        // suspend() cancelled self.cancellation_token to stop the old task;
+        // without a fresh token here the new task's select! would see it
+        // already cancelled and exit on its first poll.
+        self.cancellation_token = CancellationToken::new();
+        self.enter()?;
+        self.spawn_event_task();
+        Ok(())
+    }
+
+    pub fn size(&self) -> Result<ratatui::layout::Rect> {
+        Ok(self.terminal.size()?)
+    }
+
+    pub fn draw(
+        &mut self,
+        f: impl FnOnce(&mut ratatui::Frame),
+    ) -> Result<ratatui::CompletedFrame> {
+        Ok(self.terminal.draw(f)?)
+    }
+
+    pub async fn next_event(&mut self) -> Option<Event> {
+        self.event_rx.recv().await
+    }
+
+    pub fn end(&mut self) -> Result<()> {
+        self.exit()?;
+        self.stop()?;
+        Ok(())
+    }
+}
+
+impl Deref for Tui {
+    type Target = ratatui::Terminal<CrosstermBackend<Stdout>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.terminal
+    }
+}
+
+impl DerefMut for Tui {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.terminal
+    }
+}
+
+impl Drop for Tui {
+    fn drop(&mut self) {
+        self.exit().ok();
+    }
+}
@@ -0,0 +1,52 @@
+use crossterm::event::{KeyEvent, MouseEvent};
+use serde::{Deserialize, Serialize};
+
+/// Raw events coming off the terminal/event task, before they've been
+/// translated into an [`Action`] by the app or a component.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Event {
+    Init,
+    Quit,
+    Error,
+    Closed,
+    Tick(u64),
+    Render,
+    FocusGained,
+    FocusLost,
+    Paste(String),
+    Key(KeyEvent),
+    Mouse(MouseEvent),
+    Resize(u16, u16),
+}
+
+/// App-level commands produced by event handling, config keybindings, or
+/// components themselves, and consumed by `App::run`'s action loop.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Action {
+    Tick(u64),
+    Render,
+    Resize(u16, u16),
+    Suspend,
+    Resume,
+    Quit,
+    Refresh,
+    Error(String),
+    Help,
+    EditPost,
+    PostDrafted(String),
+    ToggleLogPanel,
+    GrowLogPanel,
+    ShrinkLogPanel,
+    ToggleFpsCounter,
+}
+
+/// Which keybinding table is active. Components report their own mode so
+/// `App` knows which section of the config to consult.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Mode {
+    #[default]
+    Timeline,
+    Thread,
+    Compose,
+    Search,
+}